@@ -55,10 +55,36 @@
 //! }
 //! ```
 //!
+//! # Scoped instances
+//!
+//! [`SHUTDOWN`] is a convenience for the common case of a single, process-wide shutdown signal.
+//! Libraries and tests that need an isolated shutdown domain (e.g. to avoid mutating global state
+//! across unrelated tests) can create their own with [`Shutdown::new`] instead. `Shutdown` is a
+//! cheaply [`Clone`]able handle, so it can be passed around and shared like any other `Arc`-backed
+//! type.
+//!
+//! # Phases
+//!
+//! [`Shutdown::listen_phase`] binds a [`ShutdownListener`] to a priority level. On [`quit`], phase
+//! `0` listeners are notified first; phase `1` listeners are only notified once every phase `0`
+//! listener has been dropped, and so on. This lets dependent subsystems (e.g. an acceptor, then
+//! in-flight requests, then a database pool) drain in a strict order instead of all at once.
+//! [`Shutdown::listen`] is just [`Shutdown::listen_phase`] with phase `0`.
+//!
+//! [`quit`]: Shutdown::quit
+//!
+//! # Child processes
+//!
+//! With the `process` feature enabled, [`Shutdown::supervise`] registers a [`tokio::process::Child`]
+//! so that it is forwarded the shutdown signal and reaped as part of the graceful drain, instead
+//! of being left running as an orphan.
+//!
 //! # Tokio dependency
 //!
-//! `ragequit` depends on [`tokio`] only for synchronization primitives. It does not depend on the
-//! tokio runtime. `ragequit` works in any asynchronous runtime.
+//! By default, `ragequit` depends on [`tokio`] only for synchronization primitives. It does not
+//! depend on the tokio runtime and works in any asynchronous runtime. The `timeout` and `process`
+//! features are the exception: they pull in `tokio::time`/`tokio::process` and, in the case of
+//! `process`, spawn a task on the current tokio runtime.
 
 #[cfg(target_family = "unix")]
 mod unix;
@@ -66,30 +92,138 @@ mod unix;
 #[cfg(target_family = "windows")]
 mod windows;
 
+#[cfg(feature = "process")]
+mod process;
+
 use core::future::Future;
 use core::pin::Pin;
-use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use core::sync::atomic::{AtomicBool, AtomicI32, AtomicU8, AtomicUsize, Ordering};
 use core::task::{Context, Poll};
+use std::collections::BTreeMap;
+use std::sync::{Arc, LazyLock, Mutex};
 
-use pin_project::{pin_project, pinned_drop};
+use pin_project::pin_project;
 use tokio::sync::futures::Notified;
 use tokio::sync::Notify;
 
 /// The global [`Shutdown`] instance.
-pub static SHUTDOWN: Shutdown = Shutdown::new();
+pub static SHUTDOWN: LazyLock<Shutdown> = LazyLock::new(Shutdown::new);
 
-/// Initializes the global [`SHUTDOWN`] instance by installing system signal handlers.
+/// Initializes the global [`SHUTDOWN`] instance by installing the default system signal
+/// handlers (`SIGINT`/`SIGTERM` on *nix, `CTRL_C_EVENT`/`CTRL_CLOSE_EVENT` on Windows).
+///
+/// To select a different set of signals, use [`Shutdown::install`] instead.
 pub fn init() {
-    #[cfg(target_family = "unix")]
-    unix::init();
+    SHUTDOWN.install().finish();
+}
 
-    #[cfg(target_family = "windows")]
-    windows::init();
+/// A portable signal that can be configured to trigger a shutdown via [`Shutdown::install`].
+///
+/// Not every variant has a counterpart on every platform; variants without a Windows console
+/// event equivalent are simply ignored when running on Windows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sig {
+    /// `SIGINT` on *nix, `CTRL_C_EVENT` on Windows.
+    Int,
+    /// `SIGTERM` on *nix, `CTRL_CLOSE_EVENT` on Windows.
+    Term,
+    /// `SIGHUP`. Unix-only.
+    Hup,
+    /// `SIGQUIT` on *nix, `CTRL_BREAK_EVENT` on Windows.
+    Quit,
+    /// `SIGUSR1`. Unix-only.
+    Usr1,
+    /// `SIGUSR2`. Unix-only.
+    Usr2,
+    /// `SIGALRM`. Unix-only.
+    Alrm,
 }
 
-#[inline]
-pub(crate) fn terminate() {
-    SHUTDOWN.quit();
+/// A builder for installing system signal handlers on a [`Shutdown`] instance.
+///
+/// Created via [`Shutdown::install`].
+pub struct ShutdownBuilder {
+    shutdown: &'static Shutdown,
+    signals: Vec<Sig>,
+}
+
+impl ShutdownBuilder {
+    /// Adds `signal` to the set of signals that trigger a shutdown.
+    #[inline]
+    pub fn signal(mut self, signal: Sig) -> Self {
+        self.signals.push(signal);
+        self
+    }
+
+    /// Adds all of `signals` to the set of signals that trigger a shutdown.
+    #[inline]
+    pub fn signals<I>(mut self, signals: I) -> Self
+    where
+        I: IntoIterator<Item = Sig>,
+    {
+        self.signals.extend(signals);
+        self
+    }
+
+    /// Installs the system signal handlers for the configured set of signals.
+    ///
+    /// If no signal was configured, this defaults to [`Sig::Int`] and [`Sig::Term`].
+    pub fn finish(self) {
+        let signals = if self.signals.is_empty() {
+            vec![Sig::Int, Sig::Term]
+        } else {
+            self.signals
+        };
+
+        #[cfg(target_family = "unix")]
+        unix::install(self.shutdown, &signals);
+
+        #[cfg(target_family = "windows")]
+        windows::install(self.shutdown, &signals);
+    }
+}
+
+// The states of `Inner`'s state machine, used to tell a freshly received signal (which should
+// start a graceful shutdown) apart from a repeated one (which should be handled according to
+// `SecondSignalAction`).
+const STATE_RUNNING: u8 = 0;
+const STATE_GRACEFUL: u8 = 1;
+const STATE_FORCED: u8 = 2;
+
+const SECOND_SIGNAL_FORCE_EXIT: u8 = 0;
+const SECOND_SIGNAL_IGNORE: u8 = 1;
+
+/// The default exit code used by [`SecondSignalAction::ForceExit`], i.e. `128 + SIGINT`.
+const DEFAULT_FORCE_EXIT_CODE: i32 = 130;
+
+/// What to do when a shutdown signal is received while a shutdown is already in progress.
+///
+/// Configured via [`Shutdown::on_second_signal`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecondSignalAction {
+    /// Immediately terminate the process with the given exit code via [`std::process::exit`].
+    ForceExit(i32),
+    /// Do nothing. Callers that want to observe the repeated signal themselves can poll
+    /// [`Shutdown::is_force_requested`].
+    Ignore,
+}
+
+/// A boxed, pinned future that owns everything it borrows from.
+///
+/// Used instead of storing a borrowed [`Notified`] next to the value it borrows from (which would
+/// need `unsafe` lifetime extension to express in a struct with no lifetime parameter, and would
+/// make soundness depend on field declaration order). An `async` block that moves its own
+/// borrowee in is self-referential, but that's sound as long as it's never polled before being
+/// pinned, which `Box::pin` guarantees.
+type OwnedNotified = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
+fn notified_from(phase: Arc<Phase>) -> OwnedNotified {
+    Box::pin(async move { phase.notify.notified().await })
+}
+
+fn started_from(inner: Arc<Inner>) -> OwnedNotified {
+    Box::pin(async move { inner.notify_start.notified().await })
 }
 
 /// A future and RAII structure waiting for a shutdown signal.
@@ -99,77 +233,292 @@ pub(crate) fn terminate() {
 ///
 /// `ShutdownListener` also doubles as a RAII strucuture. While this instance is kept alive, the
 /// process will not exit.
-#[pin_project(PinnedDrop)]
 pub struct ShutdownListener {
-    #[pin]
-    notified: Notified<'static>,
+    notified: OwnedNotified,
+    // Wakes this listener the moment a shutdown starts, so it can activate its own phase (see
+    // `poll` below) instead of relying solely on an OS signal handler to do so, since the
+    // handler itself must not touch the `phases` map (see `Inner::advance`). Cleared once
+    // observed, since a completed future isn't meant to be polled again.
+    started: Option<OwnedNotified>,
+    phase: Arc<Phase>,
+    inner: Arc<Inner>,
 }
 
 impl ShutdownListener {
-    /// Returns `true` if a shutdown signal has been received yet.
+    /// Returns `true` once this listener's phase has been notified of a shutdown.
     ///
     /// Once this function returns `true`, all future calls will also return `true` and calls to
-    /// [`poll`] will resolve immediately.
+    /// [`poll`] will resolve immediately. For a [`Shutdown::listen_phase`] listener, this can
+    /// return `true` later than [`Shutdown::is_force_requested`]/the shutdown signal itself, since
+    /// later phases only get notified once every earlier phase has drained.
     ///
     /// [`poll`]: Future::poll
     #[inline]
     pub fn is_in_progress(&self) -> bool {
-        SHUTDOWN.in_progress.load(Ordering::Acquire)
+        self.phase.activated.load(Ordering::Acquire)
     }
 }
 
 impl Future for ShutdownListener {
     type Output = ();
 
-    #[inline]
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         if self.is_in_progress() {
             return Poll::Ready(());
         }
 
-        self.project().notified.poll(cx)
+        // None of `ShutdownListener`'s fields need structural pinning: `notified`/`started` are
+        // already pinned via `Box::pin`, and `phase`/`inner` are plain `Arc`s. So the whole struct
+        // is `Unpin`, and unwrapping the outer `Pin` here is just a projection, not a guarantee
+        // we're giving up.
+        let this = Pin::into_inner(self);
+
+        if let Some(started) = this.started.as_mut() {
+            if started.as_mut().poll(cx).is_ready() {
+                this.started = None;
+            }
+        }
+
+        // An OS signal handler only flips atomic state and wakes `started`; it never calls
+        // `advance` itself (taking a lock from signal-handler context risks deadlocking a thread
+        // that was interrupted while already holding it). Do that bookkeeping here instead, in
+        // ordinary task context, now that we've noticed a shutdown may have started.
+        if this.inner.in_progress() {
+            this.inner.advance();
+
+            if this.phase.activated.load(Ordering::Acquire) {
+                return Poll::Ready(());
+            }
+        }
+
+        this.notified.as_mut().poll(cx)
     }
 }
 
-#[pinned_drop]
-impl PinnedDrop for ShutdownListener {
+impl Drop for ShutdownListener {
     #[inline]
-    fn drop(self: Pin<&mut Self>) {
-        SHUTDOWN.dec();
+    fn drop(&mut self) {
+        let prev = self.phase.counter.fetch_sub(1, Ordering::AcqRel);
+
+        if prev == 1 {
+            self.inner.advance();
+        }
     }
 }
 
-/// A group of [`ShutdownListener`]s waiting for a shutdown signal.
+/// Per-phase state for [`Shutdown::listen_phase`].
 #[derive(Debug)]
-pub struct Shutdown {
-    in_progress: AtomicBool,
+struct Phase {
     counter: AtomicUsize,
-    notify_shutdown: Notify,
+    activated: AtomicBool,
+    notify: Notify,
+}
+
+impl Phase {
+    fn new() -> Self {
+        Self {
+            counter: AtomicUsize::new(0),
+            activated: AtomicBool::new(false),
+            notify: Notify::new(),
+        }
+    }
+}
+
+/// The shared state behind a [`Shutdown`] handle.
+#[derive(Debug)]
+struct Inner {
+    state: AtomicU8,
+    phases: Mutex<BTreeMap<u32, Arc<Phase>>>,
+    /// Notified once, the moment a shutdown starts (i.e. `state` leaves `STATE_RUNNING`). Unlike
+    /// `notify_done`/a `Phase`'s own `notify`, this fires immediately instead of only once
+    /// draining reaches a particular point, so it's what wakes up a future that was parked
+    /// *before* the shutdown started and has nothing else to re-check it.
+    notify_start: Notify,
     notify_done: Notify,
+    // Counts received *signals* specifically, separately from `state`. `state` also moves off
+    // `STATE_RUNNING` for a programmatic `Shutdown::quit()`, which must not by itself make the
+    // next signal look like a repeat.
+    signal_count: AtomicUsize,
+    second_signal_action: AtomicU8,
+    second_signal_code: AtomicI32,
 }
 
-impl Shutdown {
+impl Inner {
     #[inline]
     const fn new() -> Self {
         Self {
-            in_progress: AtomicBool::new(false),
-            counter: AtomicUsize::new(0),
-            notify_shutdown: Notify::const_new(),
+            state: AtomicU8::new(STATE_RUNNING),
+            phases: Mutex::new(BTreeMap::new()),
+            notify_start: Notify::const_new(),
             notify_done: Notify::const_new(),
+            signal_count: AtomicUsize::new(0),
+            second_signal_action: AtomicU8::new(SECOND_SIGNAL_FORCE_EXIT),
+            second_signal_code: AtomicI32::new(DEFAULT_FORCE_EXIT_CODE),
+        }
+    }
+
+    #[inline]
+    fn in_progress(&self) -> bool {
+        self.state.load(Ordering::Acquire) != STATE_RUNNING
+    }
+
+    /// Returns the state for `phase_num`, creating it if this is the first listener on it.
+    fn phase(&self, phase_num: u32) -> Arc<Phase> {
+        self.phases
+            .lock()
+            .unwrap()
+            .entry(phase_num)
+            .or_insert_with(|| Arc::new(Phase::new()))
+            .clone()
+    }
+
+    /// The number of outstanding [`ShutdownListener`]s across all phases.
+    fn total_remaining(&self) -> usize {
+        self.phases
+            .lock()
+            .unwrap()
+            .values()
+            .map(|phase| phase.counter.load(Ordering::Acquire))
+            .sum()
+    }
+
+    /// Notifies phases in ascending order, stopping at the first phase that hasn't fully drained
+    /// yet. Once every registered phase has drained, wakes up [`Wait`]/[`WaitTimeoutWith`].
+    ///
+    /// Takes the `phases` lock, so this must never be called directly from an OS signal handler:
+    /// a signal can interrupt a thread that already holds the lock, and `std::sync::Mutex` isn't
+    /// reentrant. [`Shutdown::signal_received`] never calls this itself; [`ShutdownListener`],
+    /// [`Wait`] and [`WaitTimeoutWith`] each call `advance` themselves from ordinary poll context
+    /// once they notice [`Inner::notify_start`] fire. See `signal_received`'s doc comment for the
+    /// caveat that firing `notify_start` from the handler is its own, narrower version of this
+    /// same problem.
+    fn advance(&self) {
+        if !self.in_progress() {
+            return;
+        }
+
+        for phase in self.phases.lock().unwrap().values() {
+            if !phase.activated.swap(true, Ordering::AcqRel) {
+                phase.notify.notify_waiters();
+            }
+
+            if phase.counter.load(Ordering::Acquire) != 0 {
+                return;
+            }
+        }
+
+        self.notify_done.notify_waiters();
+    }
+}
+
+/// A group of [`ShutdownListener`]s waiting for a shutdown signal.
+///
+/// `Shutdown` is a cheaply [`Clone`]able handle backed by an [`Arc`]; cloning it yields another
+/// handle to the same underlying shutdown state. Use [`Shutdown::new`] to create an independent,
+/// scoped instance, or the global [`SHUTDOWN`] for the common process-wide case.
+#[derive(Clone, Debug)]
+pub struct Shutdown {
+    inner: Arc<Inner>,
+}
+
+impl Shutdown {
+    /// Creates a new, independent `Shutdown` instance.
+    ///
+    /// Unlike [`SHUTDOWN`], listeners created from the returned instance are only notified by
+    /// calls to [`quit`] on that same instance (or its clones).
+    ///
+    /// [`quit`]: Shutdown::quit
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner::new()),
         }
     }
 
+    /// Returns `true` once a second shutdown signal has been received while shutdown was already
+    /// in progress.
     #[inline]
-    fn inc(&self) {
-        self.counter.fetch_add(1, Ordering::Acquire);
+    pub fn is_force_requested(&self) -> bool {
+        self.inner.state.load(Ordering::Acquire) == STATE_FORCED
     }
 
+    /// Configures what happens when a shutdown signal is received while shutdown is already in
+    /// progress.
+    ///
+    /// The default is [`SecondSignalAction::ForceExit`] with exit code `130`.
     #[inline]
-    fn dec(&self) {
-        let prev = self.counter.fetch_sub(1, Ordering::AcqRel);
+    pub fn on_second_signal(&self, action: SecondSignalAction) {
+        match action {
+            SecondSignalAction::ForceExit(code) => {
+                self.inner.second_signal_code.store(code, Ordering::Release);
+                self.inner
+                    .second_signal_action
+                    .store(SECOND_SIGNAL_FORCE_EXIT, Ordering::Release);
+            }
+            SecondSignalAction::Ignore => {
+                self.inner
+                    .second_signal_action
+                    .store(SECOND_SIGNAL_IGNORE, Ordering::Release);
+            }
+        }
+    }
+
+    /// Advances the shutdown state machine in response to a received signal.
+    ///
+    /// The first signal starts a graceful shutdown just like [`Shutdown::quit`]. Any further
+    /// signal is treated as a request to force-quit and is handled according to the configured
+    /// [`SecondSignalAction`]. A programmatic [`Shutdown::quit`] call doesn't count as a signal
+    /// here, so the user's first Ctrl+C after calling `quit()` themselves still starts a normal
+    /// graceful shutdown instead of force-exiting immediately.
+    ///
+    /// Called directly from an installed OS signal handler. It never takes the `phases` lock
+    /// itself (see [`Inner::advance`]), leaving the rest of the bookkeeping to whatever is polling
+    /// this instance's listeners/waiters — but it is not fully async-signal-safe in the strict
+    /// POSIX sense: [`Shutdown::start`] wakes [`Inner::notify_start`] via `Notify::notify_waiters`,
+    /// which briefly locks that `Notify`'s internal waiter list. If the signal interrupts a thread
+    /// that's already mid-register/deregister/poll of a `Notified` on that same `Notify`, this call
+    /// could deadlock that thread. This hazard predates this function (the original handler here
+    /// notified a `Notify` directly too); it isn't eliminated, only narrowed to `notify_start`
+    /// specifically. A fully signal-safe implementation would need to defer the wake out of
+    /// handler context entirely (e.g. via a self-pipe), which is a larger change than this crate
+    /// currently makes. The force-exit branch below has a version of the same caveat:
+    /// `std::process::exit` also runs from this same handler, and isn't on the POSIX
+    /// async-signal-safe list either (it runs `atexit` handlers and flushes C stdio buffers).
+    fn signal_received(&self) {
+        match self.inner.signal_count.fetch_add(1, Ordering::AcqRel) {
+            0 => {
+                self.start();
+            }
+            _ => {
+                self.inner.state.store(STATE_FORCED, Ordering::Release);
+
+                if self.inner.second_signal_action.load(Ordering::Acquire)
+                    == SECOND_SIGNAL_FORCE_EXIT
+                {
+                    std::process::exit(self.inner.second_signal_code.load(Ordering::Acquire));
+                }
+            }
+        }
+    }
 
-        if self.in_progress.load(Ordering::Acquire) && prev == 1 {
-            self.notify_done.notify_waiters();
+    /// Returns a [`ShutdownBuilder`] for installing system signal handlers that trigger this
+    /// `Shutdown` instance.
+    ///
+    /// By default no signal is installed until [`ShutdownBuilder::finish`] is called, at which
+    /// point [`Sig::Int`] and [`Sig::Term`] are used unless a different set was configured via
+    /// [`ShutdownBuilder::signal`] or [`ShutdownBuilder::signals`].
+    ///
+    /// System signal handlers are a process-wide resource: only one `Shutdown` instance can have
+    /// its handlers installed at a time, and finishing another `install` (on this or another
+    /// instance) redirects every installed handler to that instance. On *nix, signals installed
+    /// by an earlier call stay installed and keep triggering a shutdown even if the later call
+    /// doesn't list them. Most processes should only call `install`/[`init`] once, on a single
+    /// instance (typically [`SHUTDOWN`]).
+    #[inline]
+    pub fn install(&'static self) -> ShutdownBuilder {
+        ShutdownBuilder {
+            shutdown: self,
+            signals: Vec::new(),
         }
     }
 
@@ -177,22 +526,70 @@ impl Shutdown {
     ///
     /// This has no effect if called multiple times.
     #[inline]
-    pub fn quit(&'static self) {
-        self.in_progress.store(true, Ordering::Release);
-        self.notify_shutdown.notify_waiters();
+    pub fn quit(&self) {
+        self.start();
+        self.inner.advance();
+    }
 
-        if self.counter.load(Ordering::Acquire) == 0 {
-            self.notify_done.notify_waiters();
+    /// Transitions the state machine from `STATE_RUNNING` to `STATE_GRACEFUL`, waking anything
+    /// parked on [`Inner::notify_start`]. Returns `true` if this call performed the transition,
+    /// i.e. this is the first request to shut down.
+    ///
+    /// Called from both ordinary context ([`Shutdown::quit`]) and, via
+    /// [`Shutdown::signal_received`], from an OS signal handler — see that function's doc comment
+    /// for the async-signal-safety caveat the latter implies.
+    #[inline]
+    fn start(&self) -> bool {
+        let transitioned = self
+            .inner
+            .state
+            .compare_exchange(
+                STATE_RUNNING,
+                STATE_GRACEFUL,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok();
+
+        if transitioned {
+            self.inner.notify_start.notify_waiters();
         }
+
+        transitioned
     }
 
-    /// Creates a new [`ShutdownListener`] on this `Shutdown` instance.
+    /// Creates a new [`ShutdownListener`] on this `Shutdown` instance, bound to phase `0`.
+    ///
+    /// Equivalent to `self.listen_phase(0)`. See the crate documentation for what phases are for.
     #[inline]
-    pub fn listen(&'static self) -> ShutdownListener {
-        self.inc();
+    pub fn listen(&self) -> ShutdownListener {
+        self.listen_phase(0)
+    }
+
+    /// Creates a new [`ShutdownListener`] on this `Shutdown` instance, bound to priority level
+    /// `phase`.
+    ///
+    /// On [`quit`], phase `0` listeners are notified first. Listeners bound to a higher phase are
+    /// only notified once every listener in every lower phase has been dropped. See the crate
+    /// documentation for more.
+    ///
+    /// [`quit`]: Shutdown::quit
+    pub fn listen_phase(&self, phase: u32) -> ShutdownListener {
+        let phase = self.inner.phase(phase);
+        phase.counter.fetch_add(1, Ordering::Acquire);
+
+        // In case shutdown is already in progress and this phase didn't exist until just now,
+        // make sure it (and any phase after it that can now drain) gets notified.
+        self.inner.advance();
+
+        let notified = notified_from(phase.clone());
+        let started = started_from(self.inner.clone());
 
         ShutdownListener {
-            notified: self.notify_shutdown.notified(),
+            notified,
+            started: Some(started),
+            phase,
+            inner: self.inner.clone(),
         }
     }
 
@@ -201,17 +598,67 @@ impl Shutdown {
     #[inline]
     pub fn wait(&self) -> Wait<'_> {
         Wait {
-            inner: self,
-            notified: self.notify_done.notified(),
+            inner: &self.inner,
+            started: Some(self.inner.notify_start.notified()),
+            notified: self.inner.notify_done.notified(),
+        }
+    }
+
+    /// Returns a future that resolves like [`Shutdown::wait`], but forces a
+    /// [`ShutdownOutcome::TimedOut`] result if not every [`ShutdownListener`] has been dropped
+    /// once the grace period elapses.
+    ///
+    /// `sleep` is only called to build the grace-period timer once a shutdown signal has actually
+    /// been received, not when this function is called. This lets runtime-agnostic callers plug
+    /// in their own timer, e.g. `shutdown.wait_timeout_with(|| my_runtime::sleep(grace))`.
+    #[inline]
+    pub fn wait_timeout_with<F, S>(&self, sleep: S) -> WaitTimeoutWith<'_, F, S>
+    where
+        S: FnOnce() -> F,
+        F: Future<Output = ()>,
+    {
+        WaitTimeoutWith {
+            inner: &self.inner,
+            started: Some(self.inner.notify_start.notified()),
+            notified: self.inner.notify_done.notified(),
+            sleep_fn: Some(sleep),
+            sleep: None,
         }
     }
+
+    /// Returns a future that resolves like [`Shutdown::wait`], but forces a
+    /// [`ShutdownOutcome::TimedOut`] result if not every [`ShutdownListener`] has been dropped
+    /// within `grace` of the shutdown signal being received.
+    ///
+    /// This is a convenience wrapper around [`Shutdown::wait_timeout_with`] that uses
+    /// [`tokio::time::sleep`] as the grace-period timer.
+    #[cfg(feature = "timeout")]
+    #[inline]
+    pub fn wait_timeout(
+        &self,
+        grace: core::time::Duration,
+    ) -> WaitTimeoutWith<'_, tokio::time::Sleep, impl FnOnce() -> tokio::time::Sleep> {
+        self.wait_timeout_with(move || tokio::time::sleep(grace))
+    }
+}
+
+impl Default for Shutdown {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// A future that completes once a shutdown signal has been received and all [`ShutdownListener`]s
 /// have been dropped.
 #[pin_project]
 pub struct Wait<'a> {
-    inner: &'a Shutdown,
+    inner: &'a Inner,
+    // Wakes this future the moment a shutdown starts, so it can run `advance` itself instead of
+    // relying on an OS signal handler to do so (see `Inner::advance`). Cleared once observed,
+    // since a `Notified` isn't meant to be polled again after completion.
+    #[pin]
+    started: Option<Notified<'a>>,
     #[pin]
     notified: Notified<'a>,
 }
@@ -219,34 +666,194 @@ pub struct Wait<'a> {
 impl<'a> Future for Wait<'a> {
     type Output = ();
 
-    #[inline]
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if self.inner.in_progress.load(Ordering::Acquire)
-            && self.inner.counter.load(Ordering::Acquire) == 0
-        {
-            return Poll::Ready(());
+        let mut this = self.project();
+
+        if let Some(started) = this.started.as_mut().as_pin_mut() {
+            if started.poll(cx).is_ready() {
+                this.started.set(None);
+            }
+        }
+
+        if this.inner.in_progress() {
+            this.inner.advance();
+
+            if this.inner.total_remaining() == 0 {
+                return Poll::Ready(());
+            }
         }
 
-        self.project().notified.poll(cx)
+        this.notified.poll(cx)
+    }
+}
+
+/// The outcome of [`Shutdown::wait_timeout`] or [`Shutdown::wait_timeout_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShutdownOutcome {
+    /// Every [`ShutdownListener`] was dropped before the grace period elapsed.
+    Graceful,
+    /// The grace period elapsed before every [`ShutdownListener`] was dropped.
+    TimedOut {
+        /// The number of [`ShutdownListener`]s that were still alive when the grace period
+        /// elapsed.
+        remaining: usize,
+    },
+}
+
+/// A future that completes once a shutdown signal has been received and all [`ShutdownListener`]s
+/// have been dropped, or a grace period elapses first.
+///
+/// Returned by [`Shutdown::wait_timeout_with`] (and [`Shutdown::wait_timeout`]).
+#[pin_project]
+pub struct WaitTimeoutWith<'a, F, S>
+where
+    S: FnOnce() -> F,
+    F: Future<Output = ()>,
+{
+    inner: &'a Inner,
+    // Wakes this future the moment a shutdown starts, even if it was parked before that
+    // happened and no phase has drained yet (so `notified` wouldn't otherwise fire). Cleared
+    // once observed, since a `Notified` isn't meant to be polled again after completion.
+    #[pin]
+    started: Option<Notified<'a>>,
+    #[pin]
+    notified: Notified<'a>,
+    sleep_fn: Option<S>,
+    #[pin]
+    sleep: Option<F>,
+}
+
+impl<'a, F, S> Future for WaitTimeoutWith<'a, F, S>
+where
+    S: FnOnce() -> F,
+    F: Future<Output = ()>,
+{
+    type Output = ShutdownOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if let Some(started) = this.started.as_mut().as_pin_mut() {
+            if started.poll(cx).is_ready() {
+                this.started.set(None);
+            }
+        }
+
+        if this.inner.in_progress() {
+            this.inner.advance();
+
+            if this.inner.total_remaining() == 0 {
+                return Poll::Ready(ShutdownOutcome::Graceful);
+            }
+
+            if this.sleep.is_none() {
+                if let Some(sleep_fn) = this.sleep_fn.take() {
+                    this.sleep.set(Some(sleep_fn()));
+                }
+            }
+
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                if sleep.poll(cx).is_ready() {
+                    return Poll::Ready(ShutdownOutcome::TimedOut {
+                        remaining: this.inner.total_remaining(),
+                    });
+                }
+            }
+        }
+
+        this.notified.poll(cx).map(|_| ShutdownOutcome::Graceful)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::Ordering;
-
     use super::SHUTDOWN;
 
     #[test]
     fn test_shutdown_counter() {
         let listener1 = SHUTDOWN.listen();
         let listener2 = SHUTDOWN.listen();
-        assert_eq!(SHUTDOWN.counter.load(Ordering::Acquire), 2);
+        assert_eq!(SHUTDOWN.inner.total_remaining(), 2);
 
         drop(listener2);
-        assert_eq!(SHUTDOWN.counter.load(Ordering::Acquire), 1);
+        assert_eq!(SHUTDOWN.inner.total_remaining(), 1);
 
         drop(listener1);
-        assert_eq!(SHUTDOWN.counter.load(Ordering::Acquire), 0);
+        assert_eq!(SHUTDOWN.inner.total_remaining(), 0);
+    }
+
+    #[test]
+    fn test_phases_drain_in_order() {
+        let shutdown = super::Shutdown::new();
+
+        let phase0 = shutdown.listen_phase(0);
+        let phase1 = shutdown.listen_phase(1);
+
+        shutdown.quit();
+
+        assert!(phase0.is_in_progress());
+        assert!(!phase1.is_in_progress());
+
+        drop(phase0);
+
+        assert!(phase1.is_in_progress());
+    }
+
+    #[tokio::test]
+    async fn test_phases_drain_cascades_through_wait() {
+        let shutdown = super::Shutdown::new();
+
+        let phase0 = shutdown.listen_phase(0);
+        let phase1 = shutdown.listen_phase(1);
+
+        let waiter = shutdown.clone();
+        let wait = tokio::task::spawn(async move { waiter.wait().await });
+
+        shutdown.quit();
+        tokio::task::yield_now().await;
+
+        // Phase 1 hasn't drained yet: phase 0 is still holding a listener alive.
+        assert!(!phase1.is_in_progress());
+        assert!(!wait.is_finished());
+
+        drop(phase0);
+        tokio::task::yield_now().await;
+
+        // Dropping the last phase-0 listener cascades: phase 1 is notified, but `wait()` still
+        // hasn't resolved, since phase 1's own listener is still alive.
+        assert!(phase1.is_in_progress());
+        assert!(!wait.is_finished());
+
+        drop(phase1);
+
+        wait.await.unwrap();
+    }
+
+    #[test]
+    fn test_scoped_shutdown_is_independent() {
+        let a = super::Shutdown::new();
+        let b = super::Shutdown::new();
+
+        let listener = a.listen();
+        a.quit();
+
+        assert!(listener.is_in_progress());
+        assert!(!b.listen().is_in_progress());
+    }
+
+    #[test]
+    fn test_signal_after_programmatic_quit_is_not_forced() {
+        let shutdown = super::Shutdown::new();
+        shutdown.on_second_signal(super::SecondSignalAction::Ignore);
+
+        shutdown.quit();
+        assert!(!shutdown.is_force_requested());
+
+        // The user's first signal after calling `quit()` themselves, not a repeat.
+        shutdown.signal_received();
+        assert!(!shutdown.is_force_requested());
+
+        shutdown.signal_received();
+        assert!(shutdown.is_force_requested());
     }
 }
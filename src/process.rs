@@ -0,0 +1,75 @@
+//! Supervise child processes so they participate in a graceful shutdown.
+//!
+//! Requires the `process` feature, which pulls in tokio's `process` and `time` features.
+
+use std::time::Duration;
+
+use tokio::process::Child;
+
+use crate::Shutdown;
+
+impl Shutdown {
+    /// Registers `child` so that it is forwarded the shutdown signal when this `Shutdown` fires,
+    /// and holds the shutdown open until the child has actually exited.
+    ///
+    /// `child` is first sent a graceful termination request (`SIGTERM` on *nix, a
+    /// `CTRL_BREAK_EVENT` on Windows); if it hasn't exited within `grace`, it is forcefully killed
+    /// instead. This spawns a task on the current Tokio runtime that outlives this call, so
+    /// supervised children are reaped even if nothing else awaits them.
+    ///
+    /// # Windows
+    ///
+    /// `GenerateConsoleCtrlEvent` only reaches processes in the targeted process group, and a
+    /// process' own process ID is only a valid process group ID for processes started with the
+    /// `CREATE_NEW_PROCESS_GROUP` creation flag (a new such process' group ID is its own PID).
+    /// `child` must therefore be spawned with that flag for the graceful request to actually
+    /// reach it, e.g. via `std::os::windows::process::CommandExt::creation_flags`. Otherwise
+    /// graceful termination is silently a no-op on Windows, and `child` is only ever forcefully
+    /// killed once `grace` elapses.
+    #[cfg(feature = "process")]
+    pub fn supervise(&self, mut child: Child, grace: Duration) {
+        let listener = self.listen();
+
+        tokio::spawn(async move {
+            tokio::pin!(listener);
+            (&mut listener).await;
+
+            terminate(&mut child, grace).await;
+        });
+    }
+}
+
+#[cfg(all(feature = "process", target_family = "unix"))]
+async fn terminate(child: &mut Child, grace: Duration) {
+    use nix::sys::signal::{self, Signal};
+    use nix::unistd::Pid;
+
+    if let Some(pid) = child.id() {
+        let _ = signal::kill(Pid::from_raw(pid as i32), Signal::SIGTERM);
+    }
+
+    if tokio::time::timeout(grace, child.wait()).await.is_err() {
+        let _ = child.start_kill();
+        let _ = child.wait().await;
+    }
+}
+
+#[cfg(all(feature = "process", target_family = "windows"))]
+async fn terminate(child: &mut Child, grace: Duration) {
+    use windows_sys::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT};
+
+    if let Some(pid) = child.id() {
+        // `pid` is only a valid process group ID if `child` was spawned with
+        // `CREATE_NEW_PROCESS_GROUP` (see `Shutdown::supervise`'s Windows note); otherwise this
+        // call fails and is ignored, same as the "no process has that ID" case.
+        //
+        // SAFETY: FFI call forwarding a `CTRL_BREAK_EVENT` to the process group identified by
+        // `pid`. Sound for any process ID.
+        let _ = unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid) };
+    }
+
+    if tokio::time::timeout(grace, child.wait()).await.is_err() {
+        let _ = child.start_kill();
+        let _ = child.wait().await;
+    }
+}
@@ -1,20 +1,52 @@
 use core::ffi::c_int;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use std::ptr;
 
 use nix::sys::signal::{sigaction, SaFlags, SigAction, SigHandler, SigSet, Signal};
 
-pub(crate) fn init() {
+use crate::{Shutdown, Sig};
+
+/// The `Shutdown` instance the installed handler forwards received signals to.
+///
+/// System signal handlers are a process-wide resource, so only one instance can be targeted at
+/// a time; the most recent call to [`install`] wins.
+static TARGET: AtomicPtr<Shutdown> = AtomicPtr::new(ptr::null_mut());
+
+pub(crate) fn install(shutdown: &'static Shutdown, signals: &[Sig]) {
+    TARGET.store(
+        shutdown as *const Shutdown as *mut Shutdown,
+        Ordering::Release,
+    );
+
     let action = SigAction::new(
         SigHandler::Handler(terminate),
         SaFlags::empty(),
         SigSet::empty(),
     );
 
-    unsafe {
-        let _ = sigaction(Signal::SIGINT, &action);
-        let _ = sigaction(Signal::SIGTERM, &action);
+    for signal in signals {
+        unsafe {
+            let _ = sigaction(to_signal(*signal), &action);
+        }
+    }
+}
+
+fn to_signal(sig: Sig) -> Signal {
+    match sig {
+        Sig::Int => Signal::SIGINT,
+        Sig::Term => Signal::SIGTERM,
+        Sig::Hup => Signal::SIGHUP,
+        Sig::Quit => Signal::SIGQUIT,
+        Sig::Usr1 => Signal::SIGUSR1,
+        Sig::Usr2 => Signal::SIGUSR2,
+        Sig::Alrm => Signal::SIGALRM,
     }
 }
 
 extern "C" fn terminate(_: c_int) {
-    super::terminate();
+    // SAFETY: `TARGET` is only ever set to a `&'static Shutdown` by `install`, so the pointee is
+    // alive for the rest of the program if the pointer is non-null.
+    if let Some(shutdown) = unsafe { TARGET.load(Ordering::Acquire).as_ref() } {
+        shutdown.signal_received();
+    }
 }
@@ -1,13 +1,70 @@
+use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+use std::ptr;
+
 use windows_sys::Win32::Foundation::BOOL;
-use windows_sys::Win32::System::Console::SetConsoleCtrlHandler;
+use windows_sys::Win32::System::Console::{
+    SetConsoleCtrlHandler, CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT, CTRL_C_EVENT,
+};
+
+use crate::{Shutdown, Sig};
+
+const INT: u8 = 1 << 0;
+const TERM: u8 = 1 << 1;
+const QUIT: u8 = 1 << 2;
+
+/// The set of console events that currently trigger a shutdown.
+static ENABLED: AtomicU8 = AtomicU8::new(0);
+
+/// The `Shutdown` instance the installed handler forwards received events to.
+///
+/// System signal handlers are a process-wide resource, so only one instance can be targeted at
+/// a time; the most recent call to [`install`] wins.
+static TARGET: AtomicPtr<Shutdown> = AtomicPtr::new(ptr::null_mut());
+
+pub(crate) fn install(shutdown: &'static Shutdown, signals: &[Sig]) {
+    TARGET.store(
+        shutdown as *const Shutdown as *mut Shutdown,
+        Ordering::Release,
+    );
+
+    let mut mask = 0;
+    for signal in signals {
+        mask |= to_bit(*signal);
+    }
+    ENABLED.store(mask, Ordering::Release);
 
-pub(crate) fn init() {
     unsafe {
         let _ = SetConsoleCtrlHandler(Some(terminate), 1);
     }
 }
 
-extern "system" fn terminate(_: u32) -> BOOL {
-    super::terminate();
+/// Maps a [`Sig`] to its Windows console event bit, if it has one.
+fn to_bit(sig: Sig) -> u8 {
+    match sig {
+        Sig::Int => INT,
+        Sig::Term => TERM,
+        Sig::Quit => QUIT,
+        // These signals have no Windows console event equivalent.
+        Sig::Hup | Sig::Usr1 | Sig::Usr2 | Sig::Alrm => 0,
+    }
+}
+
+extern "system" fn terminate(event: u32) -> BOOL {
+    let bit = match event {
+        CTRL_C_EVENT => INT,
+        CTRL_CLOSE_EVENT => TERM,
+        CTRL_BREAK_EVENT => QUIT,
+        _ => return 0,
+    };
+
+    if ENABLED.load(Ordering::Acquire) & bit == 0 {
+        return 0;
+    }
+
+    // SAFETY: `TARGET` is only ever set to a `&'static Shutdown` by `install`, so the pointee is
+    // alive for the rest of the program if the pointer is non-null.
+    if let Some(shutdown) = unsafe { TARGET.load(Ordering::Acquire).as_ref() } {
+        shutdown.signal_received();
+    }
     1
 }
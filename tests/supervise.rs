@@ -0,0 +1,30 @@
+#![cfg(all(feature = "process", unix))]
+
+use std::time::{Duration, Instant};
+
+use ragequit::SHUTDOWN;
+use tokio::process::Command;
+use tokio::time::sleep_until;
+
+#[tokio::test]
+async fn test_supervise_force_kills_after_grace() {
+    // Ignores SIGTERM, so supervise() has to fall back to a hard kill once the grace
+    // period elapses.
+    let child = Command::new("sh")
+        .args(["-c", "trap '' TERM; sleep 30"])
+        .spawn()
+        .unwrap();
+
+    SHUTDOWN.supervise(child, Duration::from_secs(1));
+
+    let now = Instant::now();
+    tokio::task::spawn(async move {
+        sleep_until((now + Duration::from_millis(200)).into()).await;
+        SHUTDOWN.quit();
+    });
+
+    SHUTDOWN.wait().await;
+    let elapsed = now.elapsed();
+    assert!(elapsed >= Duration::from_millis(200));
+    assert!(elapsed < Duration::from_secs(30));
+}
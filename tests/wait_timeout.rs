@@ -0,0 +1,31 @@
+#![cfg(feature = "timeout")]
+
+use std::time::{Duration, Instant};
+
+use ragequit::{Shutdown, ShutdownOutcome};
+use tokio::time::sleep_until;
+
+#[tokio::test]
+async fn test_wait_timeout_stuck_listener() {
+    let shutdown = Shutdown::new();
+
+    // Never dropped, so the grace period can't resolve gracefully on its own.
+    let listener = shutdown.listen();
+
+    // `wait_timeout` is parked *before* the shutdown starts, so the grace-period timer can only
+    // get armed by something waking this future up once the signal arrives, not by the state
+    // already being in progress on the first poll.
+    let now = Instant::now();
+    let quitter = shutdown.clone();
+    tokio::task::spawn(async move {
+        sleep_until((now + Duration::from_millis(100)).into()).await;
+        quitter.quit();
+    });
+
+    let outcome = shutdown.wait_timeout(Duration::from_millis(100)).await;
+
+    assert_eq!(outcome, ShutdownOutcome::TimedOut { remaining: 1 });
+    assert!(now.elapsed() >= Duration::from_millis(200));
+
+    drop(listener);
+}